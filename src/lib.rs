@@ -12,6 +12,7 @@ use alkanes_support::{
 };
 
 use anyhow::{anyhow, Result};
+use sha2::{Digest, Sha256};
 use std::sync::Arc;
 
 mod svg_generator;
@@ -20,6 +21,66 @@ use svg_generator::SvgGenerator;
 /// Orbital template ID
 const DOGI_ORBITAL_TEMPLATE_ID: u128 = 0x378;
 
+/// Total number of distinct trait combinations the weighted tables below can
+/// produce (6 backgrounds × 6 bodies × 6 eyes × 6 accessories × 5 rarities).
+/// This is the real ceiling on unique mints once content-hash dedup is
+/// enforced: `max_mints` must never exceed it, or mints near the tail would
+/// keep failing as "duplicate orbital content" well before the advertised
+/// supply was reached.
+const TRAIT_COMBINATION_SPACE: u128 = 6 * 6 * 6 * 6 * 5;
+
+/// Collection size used until `max_mints_pointer` is explicitly set to something else.
+const DEFAULT_MAX_MINTS: u128 = TRAIT_COMBINATION_SPACE;
+
+/// Cap on re-rolls `resolve_unique_content` will try for a single index before
+/// giving up and reporting a duplicate, so a pathological run can't loop forever.
+const MAX_COLLISION_ATTEMPTS: u128 = 1_000;
+
+/// Weighted trait tables: (value, weight). Higher weight draws more often.
+const BACKGROUND_TABLE: &[(&str, u32)] = &[
+  ("Cosmic Purple", 15),
+  ("Neon Green", 20),
+  ("Electric Blue", 20),
+  ("Sunset Orange", 20),
+  ("Matrix Black", 15),
+  ("Golden Dawn", 10),
+];
+
+const BODY_TABLE: &[(&str, u32)] = &[
+  ("Golden Shiba", 15),
+  ("Silver Shiba", 20),
+  ("Rainbow Shiba", 15),
+  ("Cyber Shiba", 20),
+  ("Platinum Shiba", 15),
+  ("Classic Shiba", 15),
+];
+
+const EYES_TABLE: &[(&str, u32)] = &[
+  ("Laser Blue", 15),
+  ("Fire Red", 20),
+  ("Galaxy Purple", 15),
+  ("Neon Green", 20),
+  ("Diamond White", 15),
+  ("Onyx Black", 15),
+];
+
+const ACCESSORY_TABLE: &[(&str, u32)] = &[
+  ("Diamond Chain", 10),
+  ("Bitcoin Crown", 10),
+  ("Rocket Pack", 20),
+  ("Holographic Collar", 25),
+  ("Infinity Gauntlet", 10),
+  ("None", 25),
+];
+
+const RARITY_TABLE: &[(&str, u32)] = &[
+  ("Mythic", 2),
+  ("Legendary", 5),
+  ("Epic", 13),
+  ("Rare", 30),
+  ("Uncommon", 50),
+];
+
 #[derive(Default)]
 pub struct GigaDogiCollection(());
 
@@ -52,6 +113,18 @@ enum GigaDogiCollectionMessage {
   #[returns(u128)]
   GetOrbitalCount,
 
+  #[opcode(103)]
+  #[returns(String)]
+  GetNameLocalized { locale: u128 },
+
+  #[opcode(104)]
+  #[returns(String)]
+  GetSymbolLocalized { locale: u128 },
+
+  #[opcode(105)]
+  #[returns(String)]
+  GetAttributesLocalized { index: u128, locale: u128 },
+
   #[opcode(999)]
   #[returns(String)]
   GetAttributes { index: u128 },
@@ -67,6 +140,22 @@ enum GigaDogiCollectionMessage {
   #[opcode(1002)]
   #[returns(String)]
   GetInstanceIdentifier { index: u128 },
+
+  #[opcode(1003)]
+  #[returns(String)]
+  GetDataUri { index: u128 },
+
+  #[opcode(1004)]
+  #[returns(Vec<u8>)]
+  GetInstanceByContentHash { hash: Vec<u8> },
+
+  #[opcode(1005)]
+  #[returns(Vec<u8>)]
+  GetTraitFrequency { index: u128 },
+
+  #[opcode(1006)]
+  #[returns(Vec<u8>)]
+  GetRarityScore { index: u128 },
 }
 
 impl Token for GigaDogiCollection {
@@ -92,6 +181,8 @@ impl GigaDogiCollection {
       value: 10u128,
     });
 
+    self.seed_i18n();
+
     Ok(response)
   }
 
@@ -130,6 +221,8 @@ impl GigaDogiCollection {
       return Err(anyhow!("Giga Dogi collection has fully minted out"));
     }
 
+    let mut seen = self.resolve_unique_content(index)?;
+
     let cellpack = Cellpack {
       target: AlkaneId {
         block: 6,
@@ -146,7 +239,9 @@ impl GigaDogiCollection {
       tx: sequence,
     };
 
-    self.add_instance(&orbital_id)?;
+    let new_count = self.add_instance(&orbital_id)?;
+    seen.set_value::<u128>(new_count);
+    self.record_trait_stats(index)?;
 
     if response.alkanes.0.len() < 1 {
       Err(anyhow!("orbital token not returned with factory"))
@@ -155,8 +250,88 @@ impl GigaDogiCollection {
     }
   }
 
+  /// Canonical JSON for an orbital's attributes: sorted keys, no whitespace.
+  /// Trait keys are emitted in alphabetical order already ("trait_type" < "value"),
+  /// so no separate sort step is needed here.
+  fn canonical_attributes_json(&self, index: u128) -> Result<Vec<u8>> {
+    let traits = self.dogi_traits(index)?;
+
+    let entries: Vec<String> = traits
+      .iter()
+      .map(|(trait_type, value)| format!(r#"{{"trait_type":"{}","value":"{}"}}"#, trait_type, value))
+      .collect();
+
+    Ok(format!(r#"{{"attributes":[{}]}}"#, entries.join(",")).into_bytes())
+  }
+
+  /// 32-byte content hash of an orbital's canonical attribute JSON, used to
+  /// detect duplicate mints and as the key for `GetInstanceByContentHash`.
+  fn content_hash(&self, index: u128) -> Result<Vec<u8>> {
+    let canonical = self.canonical_attributes_json(index)?;
+    Ok(Sha256::digest(&canonical).to_vec())
+  }
+
+  fn collision_attempt_pointer(&self, index: u128) -> StoragePointer {
+    StoragePointer::from_keyword("/collision_attempt/").select(&index.to_le_bytes().to_vec())
+  }
+
+  /// Finds a content hash for `index` that no earlier mint already claimed.
+  /// The trait draw for a given index is deterministic, so a bare collision
+  /// would brick that index (and every retry of it) forever; instead we
+  /// bump a per-index salt that `dogi_traits` mixes into its seed and re-roll,
+  /// persisting the winning salt so the traits stay stable once minted.
+  fn resolve_unique_content(&self, index: u128) -> Result<StoragePointer> {
+    let mut attempt_pointer = self.collision_attempt_pointer(index);
+
+    loop {
+      let hash = self.content_hash(index)?;
+      let seen = self.seen_pointer(&hash);
+
+      if seen.get_value::<u128>() == 0 {
+        return Ok(seen);
+      }
+
+      let attempt = attempt_pointer.get_value::<u128>();
+      if attempt + 1 >= MAX_COLLISION_ATTEMPTS {
+        return Err(anyhow!("duplicate orbital content"));
+      }
+
+      attempt_pointer.set_value::<u128>(attempt + 1);
+    }
+  }
+
+  /// Per-(trait_type, value) mint counter, keyed as /traits/<trait_type>/<value>.
+  fn trait_counter_pointer(&self, trait_type: &str, value: &str) -> StoragePointer {
+    StoragePointer::from_keyword("/traits/")
+      .select(&trait_type.as_bytes().to_vec())
+      .select(&value.as_bytes().to_vec())
+  }
+
+  fn record_trait_stats(&self, index: u128) -> Result<()> {
+    for (trait_type, value) in self.dogi_traits(index)?.iter() {
+      let mut pointer = self.trait_counter_pointer(trait_type, value);
+      let count = pointer.get_value::<u128>();
+      pointer.set_value::<u128>(count + 1);
+    }
+
+    Ok(())
+  }
+
+  fn max_mints_pointer(&self) -> StoragePointer {
+    StoragePointer::from_keyword("/max_mints")
+  }
+
   fn max_mints(&self) -> u128 {
-    5 // Supply max de 5 NFTs
+    let configured = self.max_mints_pointer().get_value::<u128>();
+
+    let requested = if configured == 0 {
+      DEFAULT_MAX_MINTS
+    } else {
+      configured
+    };
+
+    // Never advertise more supply than the trait tables can back with unique content.
+    requested.min(TRAIT_COMBINATION_SPACE)
   }
 
   fn max_mint_per_block(&self) -> u32 {
@@ -216,67 +391,162 @@ impl GigaDogiCollection {
     Ok(response)
   }
 
+  fn get_name_localized(&self, locale: u128) -> Result<CallResponse> {
+    let context = self.context()?;
+    let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+    let locale_code = Self::locale_code(locale);
+    response.data = self.translate(&locale_code, "name", &self.name()).into_bytes();
+
+    Ok(response)
+  }
+
+  fn get_symbol_localized(&self, locale: u128) -> Result<CallResponse> {
+    let context = self.context()?;
+    let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+    let locale_code = Self::locale_code(locale);
+    response.data = self.translate(&locale_code, "symbol", &self.symbol()).into_bytes();
+
+    Ok(response)
+  }
+
+  fn get_attributes_localized(&self, index: u128, locale: u128) -> Result<CallResponse> {
+    let context = self.context()?;
+    let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+    let locale_code = Self::locale_code(locale);
+    let traits = self.dogi_traits(index)?;
+
+    let entries: Vec<String> = traits
+      .iter()
+      .map(|(trait_type, value)| {
+        let localized_trait_type =
+          self.translate(&locale_code, &format!("trait_type:{}", trait_type), trait_type);
+        let localized_value = self.translate(&locale_code, &format!("value:{}", value), value);
+        format!(
+          r#"{{"trait_type": "{}", "value": "{}"}}"#,
+          localized_trait_type, localized_value
+        )
+      })
+      .collect();
+
+    response.data = format!(r#"{{"attributes": [{}]}}"#, entries.join(", ")).into_bytes();
+
+    Ok(response)
+  }
+
+  // Thumbnail fallback only: the Cloudinary set covers exactly the original 5
+  // hand-uploaded renders and errors for every index beyond that, even though
+  // the index mints and renders fine via `GetDataUri`. Callers should treat
+  // that error as "no thumbnail", not "orbital doesn't exist".
   fn get_data(&self, index: u128) -> Result<CallResponse> {
     let context = self.context()?;
     let mut response = CallResponse::forward(&context.incoming_alkanes);
 
-    // Retourne l'URL Cloudinary pour l'image du Dogi
     let cloudinary_url = self.get_cloudinary_url(index)?;
     response.data = cloudinary_url.into_bytes();
     Ok(response)
   }
 
-  fn generate_dogi_attributes(&self, index: u128) -> Result<String> {
-    if index >= 5 {
+  fn data_uri_pointer(&self, index: u128) -> StoragePointer {
+    StoragePointer::from_keyword("/datauri/").select(&index.to_le_bytes().to_vec())
+  }
+
+  fn get_data_uri(&self, index: u128) -> Result<CallResponse> {
+    let context = self.context()?;
+    let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+    let mut pointer = self.data_uri_pointer(index);
+    let cached = pointer.get();
+
+    // The pointer caches the gzip-compressed SVG (cheap to store); the data URI
+    // itself must carry the plain SVG, since there's no out-of-band way to tell
+    // a wallet decoding `data:image/svg+xml;base64,...` to gunzip it first.
+    let svg = if cached.len() > 0 {
+      SvgGenerator::decompress(&cached)?
+    } else {
+      let traits = self.dogi_traits(index)?;
+      let svg = SvgGenerator::render(&traits);
+      let compressed = SvgGenerator::compress(&svg)?;
+      pointer.set(Arc::new(compressed));
+      svg
+    };
+
+    response.data = format!("data:image/svg+xml;base64,{}", encode_base64(svg.as_bytes())).into_bytes();
+    Ok(response)
+  }
+
+  /// Deterministically draws this token's 5 traits from the weighted tables,
+  /// seeded from its mint index mixed with the collection's own AlkaneId and
+  /// the index's collision salt (bumped by `resolve_unique_content` at mint
+  /// time). Once an index is minted its salt is fixed, so its traits stay
+  /// stable across repeated calls and reorgs.
+  fn dogi_traits(&self, index: u128) -> Result<Vec<(&'static str, &'static str)>> {
+    if index >= self.max_mints() {
       return Err(anyhow!("Index out of bounds for Giga Dogi collection"));
     }
 
-    // Définit les traits pour chaque Dogi (5 traits par NFT)
-    let dogi_traits = match index {
-      0 => r#"{"attributes": [
-        {"trait_type": "Background", "value": "Cosmic Purple"},
-        {"trait_type": "Body", "value": "Golden Shiba"},
-        {"trait_type": "Eyes", "value": "Laser Blue"},
-        {"trait_type": "Accessory", "value": "Diamond Chain"},
-        {"trait_type": "Rarity", "value": "Legendary"}
-      ]}"#,
-      1 => r#"{"attributes": [
-        {"trait_type": "Background", "value": "Neon Green"},
-        {"trait_type": "Body", "value": "Silver Shiba"},
-        {"trait_type": "Eyes", "value": "Fire Red"},
-        {"trait_type": "Accessory", "value": "Bitcoin Crown"},
-        {"trait_type": "Rarity", "value": "Epic"}
-      ]}"#,
-      2 => r#"{"attributes": [
-        {"trait_type": "Background", "value": "Electric Blue"},
-        {"trait_type": "Body", "value": "Rainbow Shiba"},
-        {"trait_type": "Eyes", "value": "Galaxy Purple"},
-        {"trait_type": "Accessory", "value": "Rocket Pack"},
-        {"trait_type": "Rarity", "value": "Rare"}
-      ]}"#,
-      3 => r#"{"attributes": [
-        {"trait_type": "Background", "value": "Sunset Orange"},
-        {"trait_type": "Body", "value": "Cyber Shiba"},
-        {"trait_type": "Eyes", "value": "Neon Green"},
-        {"trait_type": "Accessory", "value": "Holographic Collar"},
-        {"trait_type": "Rarity", "value": "Uncommon"}
-      ]}"#,
-      4 => r#"{"attributes": [
-        {"trait_type": "Background", "value": "Matrix Black"},
-        {"trait_type": "Body", "value": "Platinum Shiba"},
-        {"trait_type": "Eyes", "value": "Diamond White"},
-        {"trait_type": "Accessory", "value": "Infinity Gauntlet"},
-        {"trait_type": "Rarity", "value": "Mythic"}
-      ]}"#,
-      _ => return Err(anyhow!("Invalid Dogi index")),
-    };
+    let context = self.context()?;
+    let attempt = self.collision_attempt_pointer(index).get_value::<u128>();
+    let mut seed = (index as u64)
+      ^ (context.myself.block as u64).wrapping_mul(0x9E3779B97F4A7C15)
+      ^ (context.myself.tx as u64).wrapping_mul(0xC2B2AE3D27D4EB4F)
+      ^ (attempt as u64).wrapping_mul(0x165667B19E3779F9);
+
+    Ok(vec![
+      ("Background", Self::draw_weighted(BACKGROUND_TABLE, &mut seed)),
+      ("Body", Self::draw_weighted(BODY_TABLE, &mut seed)),
+      ("Eyes", Self::draw_weighted(EYES_TABLE, &mut seed)),
+      ("Accessory", Self::draw_weighted(ACCESSORY_TABLE, &mut seed)),
+      ("Rarity", Self::draw_weighted(RARITY_TABLE, &mut seed)),
+    ])
+  }
+
+  /// splitmix64: advances `seed` in place and returns the next pseudo-random u64.
+  fn next_prng(seed: &mut u64) -> u64 {
+    *seed = seed.wrapping_add(0x9E3779B97F4A7C15);
+    let mut z = *seed;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+    z ^ (z >> 31)
+  }
+
+  /// Picks one entry from a cumulative-weight table, re-advancing `seed` for the next slot.
+  fn draw_weighted(table: &[(&'static str, u32)], seed: &mut u64) -> &'static str {
+    let total: u32 = table.iter().map(|(_, weight)| *weight).sum();
+    let roll = (Self::next_prng(seed) % total as u64) as u32;
+
+    let mut cumulative = 0u32;
+    for (value, weight) in table.iter() {
+      cumulative += weight;
+      if roll < cumulative {
+        return value;
+      }
+    }
+
+    table.last().expect("weighted table must not be empty").0
+  }
+
+  fn generate_dogi_attributes(&self, index: u128) -> Result<String> {
+    let traits = self.dogi_traits(index)?;
 
-    Ok(dogi_traits.to_string())
+    let entries: Vec<String> = traits
+      .iter()
+      .map(|(trait_type, value)| {
+        format!(r#"{{"trait_type": "{}", "value": "{}"}}"#, trait_type, value)
+      })
+      .collect();
+
+    Ok(format!(r#"{{"attributes": [{}]}}"#, entries.join(", ")))
   }
 
+  /// Permanently limited to the original 5 pre-rendered thumbnails; it does not
+  /// grow with `max_mints`. Indices >= 5 are valid orbitals with real on-chain
+  /// attributes and an SVG via `GetDataUri` — they just have no Cloudinary thumbnail.
   fn get_cloudinary_url(&self, index: u128) -> Result<String> {
     if index >= 5 {
-      return Err(anyhow!("Index out of bounds for Giga Dogi collection"));
+      return Err(anyhow!("no Cloudinary thumbnail for this index (only the original 5 orbitals have one)"));
     }
 
     // URLs Cloudinary pour chaque Giga Dogi
@@ -291,6 +561,50 @@ impl GigaDogiCollection {
     Ok(cloudinary_urls[index as usize].to_string())
   }
 
+  /// Unpacks a locale param (short ASCII code packed into the low-order bytes
+  /// of a u128 read big-endian, e.g. 0x6672 for "fr") back into its string form.
+  fn locale_code(locale: u128) -> String {
+    let code: Vec<u8> = locale
+      .to_be_bytes()
+      .into_iter()
+      .skip_while(|&b| b == 0)
+      .collect();
+
+    String::from_utf8_lossy(&code).to_string()
+  }
+
+  fn i18n_pointer(&self, locale: &str, key: &str) -> StoragePointer {
+    StoragePointer::from_keyword("/i18n/")
+      .select(&locale.as_bytes().to_vec())
+      .select(&key.as_bytes().to_vec())
+  }
+
+  fn set_translation(&self, locale: &str, key: &str, value: &str) {
+    self.i18n_pointer(locale, key).set(Arc::new(value.as_bytes().to_vec()));
+  }
+
+  /// Looks up a (locale, key) translation, falling back to the canonical English string.
+  fn translate(&self, locale: &str, key: &str, fallback: &str) -> String {
+    let bytes = self.i18n_pointer(locale, key).get();
+
+    if bytes.len() == 0 {
+      fallback.to_string()
+    } else {
+      String::from_utf8_lossy(&bytes).to_string()
+    }
+  }
+
+  /// Seeds the French strings already implied by this contract's French code comments,
+  /// so the collection ships with at least `en` (the canonical defaults) and `fr`.
+  fn seed_i18n(&self) {
+    self.set_translation("fr", "name", "Giga Dogi");
+    self.set_translation("fr", "trait_type:Background", "Arrière-plan");
+    self.set_translation("fr", "trait_type:Body", "Corps");
+    self.set_translation("fr", "trait_type:Eyes", "Yeux");
+    self.set_translation("fr", "trait_type:Accessory", "Accessoire");
+    self.set_translation("fr", "trait_type:Rarity", "Rareté");
+  }
+
   fn instances_pointer(&self) -> StoragePointer {
     StoragePointer::from_keyword("/instances")
   }
@@ -389,6 +703,103 @@ impl GigaDogiCollection {
     response.data = instance_str.into_bytes();
     Ok(response)
   }
+
+  fn get_instance_by_content_hash(&self, hash: Vec<u8>) -> Result<CallResponse> {
+    let context = self.context()?;
+    let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+    let seen = self.seen_pointer(&hash);
+    let instance_count = seen.get_value::<u128>();
+
+    if instance_count == 0 {
+      return Err(anyhow!("no orbital minted with this content hash"));
+    }
+
+    // seen_pointer stores the 1-based instance count written by add_instance,
+    // while lookup_instance expects the 0-based mint index and re-adds 1 itself.
+    let instance_id = self.lookup_instance(instance_count - 1)?;
+
+    let mut bytes = Vec::with_capacity(32);
+    bytes.extend_from_slice(&instance_id.block.to_le_bytes());
+    bytes.extend_from_slice(&instance_id.tx.to_le_bytes());
+
+    response.data = bytes;
+    Ok(response)
+  }
+
+  fn get_trait_frequency(&self, index: u128) -> Result<CallResponse> {
+    let context = self.context()?;
+    let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+    let traits = self.dogi_traits(index)?;
+    let mut bytes = Vec::with_capacity(traits.len() * 16);
+
+    // The counter only includes this token's own mint once `index` has actually
+    // been minted (instances_count() covers 0..instances_count()); querying an
+    // unminted index's prospective traits must not subtract a mint that never happened.
+    let is_minted = index < self.instances_count();
+
+    for (trait_type, value) in traits.iter() {
+      let count = self.trait_counter_pointer(trait_type, value).get_value::<u128>();
+      let other_holders = if is_minted { count.saturating_sub(1) } else { count };
+      bytes.extend_from_slice(&other_holders.to_le_bytes());
+    }
+
+    response.data = bytes;
+    Ok(response)
+  }
+
+  fn get_rarity_score(&self, index: u128) -> Result<CallResponse> {
+    let context = self.context()?;
+    let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+    let total_minted = self.instances_count();
+
+    // Rarity is computed on read, not cached, since the frequency table mutates as minting proceeds.
+    let score = if total_minted == 0 {
+      0u128
+    } else {
+      self.dogi_traits(index)?.iter().fold(0u128, |acc, (trait_type, value)| {
+        let frequency = self.trait_counter_pointer(trait_type, value).get_value::<u128>();
+        if frequency == 0 {
+          acc
+        } else {
+          acc + total_minted / frequency
+        }
+      })
+    };
+
+    response.data = score.to_le_bytes().to_vec();
+    Ok(response)
+  }
+}
+
+/// Standard base64 (RFC 4648) encoder, used to embed the plain SVG markup in a data URI.
+fn encode_base64(bytes: &[u8]) -> String {
+  const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+  let mut out = String::with_capacity((bytes.len() + 2) / 3 * 4);
+
+  for chunk in bytes.chunks(3) {
+    let b0 = chunk[0];
+    let b1 = *chunk.get(1).unwrap_or(&0);
+    let b2 = *chunk.get(2).unwrap_or(&0);
+
+    out.push(ALPHABET[(b0 >> 2) as usize] as char);
+    out.push(ALPHABET[(((b0 & 0x03) << 4) | (b1 >> 4)) as usize] as char);
+    out.push(if chunk.len() > 1 {
+      ALPHABET[(((b1 & 0x0f) << 2) | (b2 >> 6)) as usize] as char
+    } else {
+      '='
+    });
+    out.push(if chunk.len() > 2 {
+      ALPHABET[(b2 & 0x3f) as usize] as char
+    } else {
+      '='
+    });
+  }
+
+  out
 }
 
 declare_alkane! {
@@ -0,0 +1,44 @@
+use anyhow::Result;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use std::io::{Read, Write};
+
+/// Renders Giga Dogi traits as a fully on-chain SVG and gzip-compresses the result.
+pub struct SvgGenerator;
+
+impl SvgGenerator {
+  /// Lays out each (trait_type, value) pair as a text row over a flat background,
+  /// so the image is fully derivable from the trait list with no external assets.
+  pub fn render(traits: &[(&str, &str)]) -> String {
+    let mut rows = String::new();
+
+    for (i, (trait_type, value)) in traits.iter().enumerate() {
+      let y = 24 + (i as u32) * 24;
+      rows.push_str(&format!(
+        "<text x=\"12\" y=\"{}\" font-family=\"monospace\" font-size=\"14\" fill=\"#fff\">{}: {}</text>",
+        y, trait_type, value
+      ));
+    }
+
+    format!(
+      "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"320\" height=\"320\" viewBox=\"0 0 320 320\">\
+<rect width=\"100%\" height=\"100%\" fill=\"#111\"/>{}</svg>",
+      rows
+    )
+  }
+
+  /// Gzip-compresses rendered SVG bytes at the best compression level.
+  pub fn compress(svg: &str) -> Result<Vec<u8>> {
+    let mut encoder = GzEncoder::new(Vec::with_capacity(svg.len()), Compression::best());
+    encoder.write_all(svg.as_bytes())?;
+    Ok(encoder.finish()?)
+  }
+
+  /// Reverses `compress`, recovering the plain SVG markup from the cached gzip bytes.
+  pub fn decompress(compressed: &[u8]) -> Result<String> {
+    let mut svg = String::new();
+    GzDecoder::new(compressed).read_to_string(&mut svg)?;
+    Ok(svg)
+  }
+}